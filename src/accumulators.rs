@@ -0,0 +1,318 @@
+//! Sufficient-statistics accumulators shared by `Analyzer` and `law_stream`.
+//!
+//! Each accumulator folds values in one at a time in O(1) space per law,
+//! independent of how many values have already been seen, so both the
+//! stateful `Analyzer` class and the out-of-core `law_stream` function can
+//! reuse the exact same incremental math.
+
+use crate::stats::{chi_square_p_value, risk_level_from_p};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use serde_json::Value;
+
+/// Leading-digit histogram backing a Benford's law analysis.
+#[derive(Default, Clone)]
+pub struct DigitHistogram {
+    counts: [u64; 9],
+    total: u64,
+}
+
+impl DigitHistogram {
+    pub fn observe(&mut self, value: f64) {
+        if !value.is_finite() {
+            return;
+        }
+        let value = value.abs();
+        if value < 1e-12 {
+            return;
+        }
+        let mut v = value;
+        while v < 1.0 {
+            v *= 10.0;
+        }
+        while v >= 10.0 {
+            v /= 10.0;
+        }
+        let digit = v as usize;
+        if (1..=9).contains(&digit) {
+            self.counts[digit - 1] += 1;
+            self.total += 1;
+        }
+    }
+
+    pub fn analyze(&self, py: Python, path: &str) -> PyResult<PyObject> {
+        if self.total == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "no data accumulated for 'benf'",
+            ));
+        }
+
+        let total = self.total as f64;
+        let mut observed_distribution = Vec::with_capacity(9);
+        let mut expected_distribution = Vec::with_capacity(9);
+        let mut chi_square = 0.0;
+        let mut mad = 0.0;
+        for digit in 1..=9usize {
+            let observed_pct = self.counts[digit - 1] as f64 / total * 100.0;
+            let expected_pct = (1.0 + 1.0 / digit as f64).log10() * 100.0;
+            let expected_count = expected_pct / 100.0 * total;
+            if expected_count > 0.0 {
+                chi_square +=
+                    (self.counts[digit - 1] as f64 - expected_count).powi(2) / expected_count;
+            }
+            mad += (observed_pct - expected_pct).abs();
+            observed_distribution.push(observed_pct);
+            expected_distribution.push(expected_pct);
+        }
+        mad /= 9.0;
+        let p_value = chi_square_p_value(chi_square, 8.0);
+
+        let dict = PyDict::new_bound(py);
+        dict.set_item("type", "BenfordAnalysis")?;
+        dict.set_item("path", path)?;
+        dict.set_item("observed_distribution", observed_distribution)?;
+        dict.set_item("expected_distribution", expected_distribution)?;
+        dict.set_item("chi_square", chi_square)?;
+        dict.set_item("p_value", p_value)?;
+        dict.set_item("mad", mad)?;
+        dict.set_item("risk_level", risk_level_from_p(p_value))?;
+        dict.set_item("total_numbers", self.total)?;
+        dict.set_item(
+            "analysis_summary",
+            format!("Benford analysis over {} accumulated values", self.total),
+        )?;
+        Ok(dict.to_object(py))
+    }
+}
+
+/// Rank-frequency table backing a Zipf's law analysis. Values are ranked by
+/// sorted magnitude (largest first) rather than by how often a value
+/// repeats, since the accumulators deal in numeric batches -- the
+/// distribution of the values themselves is what Zipf's law describes here,
+/// not a word-frequency count.
+#[derive(Default, Clone)]
+pub struct RankFrequency {
+    values: Vec<f64>,
+}
+
+impl RankFrequency {
+    pub fn observe(&mut self, value: f64) {
+        if value > 0.0 {
+            self.values.push(value);
+        }
+    }
+
+    pub fn analyze(&self, py: Python, path: &str) -> PyResult<PyObject> {
+        if self.values.is_empty() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "no data accumulated for 'zipf'",
+            ));
+        }
+
+        let mut sorted = self.values.clone();
+        sorted.sort_unstable_by(|a, b| b.partial_cmp(a).unwrap());
+
+        let n = sorted.len();
+        let xs: Vec<f64> = (1..=n).map(|rank| (rank as f64).ln()).collect();
+        let ys: Vec<f64> = sorted.iter().map(|&v| v.ln()).collect();
+        let mean_x = xs.iter().sum::<f64>() / n as f64;
+        let mean_y = ys.iter().sum::<f64>() / n as f64;
+
+        let mut cov = 0.0;
+        let mut var_x = 0.0;
+        let mut var_y = 0.0;
+        for i in 0..n {
+            let dx = xs[i] - mean_x;
+            let dy = ys[i] - mean_y;
+            cov += dx * dy;
+            var_x += dx * dx;
+            var_y += dy * dy;
+        }
+
+        let slope = if var_x > 0.0 { cov / var_x } else { 0.0 };
+        let zipf_coefficient = -slope;
+        let correlation_coefficient = if var_x > 0.0 && var_y > 0.0 {
+            cov / (var_x.sqrt() * var_y.sqrt())
+        } else {
+            0.0
+        };
+        let deviation_score = (1.0 - correlation_coefficient.abs()) * 100.0;
+
+        let dict = PyDict::new_bound(py);
+        dict.set_item("type", "ZipfAnalysis")?;
+        dict.set_item("path", path)?;
+        dict.set_item("zipf_coefficient", zipf_coefficient)?;
+        dict.set_item("correlation_coefficient", correlation_coefficient)?;
+        dict.set_item("deviation_score", deviation_score)?;
+        dict.set_item(
+            "risk_level",
+            risk_level_from_p(1.0 - correlation_coefficient.abs()),
+        )?;
+        dict.set_item("total_items", n)?;
+        dict.set_item(
+            "analysis_summary",
+            format!("Zipf analysis over {n} accumulated values"),
+        )?;
+        Ok(dict.to_object(py))
+    }
+}
+
+/// Running count/mean/M2/M3/M4 via Welford's online algorithm, extended to
+/// higher moments. Backs both Normal (moments of the values themselves) and
+/// Poisson (moments of per-batch event counts) analyses.
+#[derive(Default, Clone)]
+pub struct WelfordMoments {
+    count: u64,
+    mean: f64,
+    m2: f64,
+    m3: f64,
+    m4: f64,
+}
+
+impl WelfordMoments {
+    pub fn observe(&mut self, x: f64) {
+        let n1 = self.count as f64;
+        self.count += 1;
+        let n = self.count as f64;
+        let delta = x - self.mean;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term1 = delta * delta_n * n1;
+
+        self.mean += delta_n;
+        self.m4 += term1 * delta_n2 * (n * n - 3.0 * n + 3.0) + 6.0 * delta_n2 * self.m2
+            - 4.0 * delta_n * self.m3;
+        self.m3 += term1 * delta_n * (n - 2.0) - 3.0 * delta_n * self.m2;
+        self.m2 += term1;
+    }
+
+    fn variance(&self) -> f64 {
+        if self.count < 2 {
+            0.0
+        } else {
+            self.m2 / self.count as f64
+        }
+    }
+
+    fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    fn skewness(&self) -> f64 {
+        let std_dev = self.std_dev();
+        if self.count == 0 || std_dev == 0.0 {
+            0.0
+        } else {
+            (self.m3 / self.count as f64) / std_dev.powi(3)
+        }
+    }
+
+    fn kurtosis(&self) -> f64 {
+        let variance = self.variance();
+        if self.count == 0 || variance == 0.0 {
+            0.0
+        } else {
+            (self.m4 / self.count as f64) / variance.powi(2) - 3.0
+        }
+    }
+
+    pub fn analyze_normal(&self, py: Python, path: &str) -> PyResult<PyObject> {
+        if self.count == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "no data accumulated for 'normal'",
+            ));
+        }
+
+        let skewness = self.skewness();
+        let kurtosis = self.kurtosis();
+        // Jarque-Bera statistic; its null distribution is chi-square(df=2),
+        // whose survival function has the closed form exp(-x/2).
+        let jarque_bera = self.count as f64 / 6.0 * (skewness.powi(2) + kurtosis.powi(2) / 4.0);
+        let p_value = (-jarque_bera / 2.0).exp().clamp(0.0, 1.0);
+
+        let dict = PyDict::new_bound(py);
+        dict.set_item("type", "NormalAnalysis")?;
+        dict.set_item("path", path)?;
+        dict.set_item("mean", self.mean)?;
+        dict.set_item("std_dev", self.std_dev())?;
+        dict.set_item("skewness", skewness)?;
+        dict.set_item("kurtosis", kurtosis)?;
+        dict.set_item("normality_test_p", p_value)?;
+        dict.set_item("risk_level", risk_level_from_p(p_value))?;
+        dict.set_item("total_numbers", self.count)?;
+        dict.set_item(
+            "analysis_summary",
+            format!("Normal analysis over {} accumulated values", self.count),
+        )?;
+        Ok(dict.to_object(py))
+    }
+
+    pub fn analyze_poisson(&self, py: Python, path: &str) -> PyResult<PyObject> {
+        if self.count == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "no data accumulated for 'poisson'",
+            ));
+        }
+
+        let lambda = self.mean;
+        let variance_ratio = if lambda > 0.0 {
+            self.variance() / lambda
+        } else {
+            0.0
+        };
+        // Index-of-dispersion test: under Poisson, (n-1)*variance_ratio is
+        // chi-square distributed with n-1 degrees of freedom.
+        let df = (self.count.saturating_sub(1)) as f64;
+        let chi_square = df * variance_ratio;
+        let p_value = chi_square_p_value(chi_square, df);
+
+        let dict = PyDict::new_bound(py);
+        dict.set_item("type", "PoissonAnalysis")?;
+        dict.set_item("path", path)?;
+        dict.set_item("lambda", lambda)?;
+        dict.set_item("variance_ratio", variance_ratio)?;
+        dict.set_item("poisson_test_p", p_value)?;
+        dict.set_item("risk_level", risk_level_from_p(p_value))?;
+        dict.set_item("total_events", self.count)?;
+        dict.set_item(
+            "analysis_summary",
+            format!("Poisson analysis over {} accumulated values", self.count),
+        )?;
+        Ok(dict.to_object(py))
+    }
+}
+
+/// Recursively flatten any JSON value into the leaf numbers it contains, so
+/// both plain number arrays and record-shaped data feed the same
+/// accumulators. Non-finite values (a string field like `"nan"`/`"inf"`
+/// parses to a real `NaN`/`Infinity`) are dropped here rather than left for
+/// every downstream accumulator to guard against individually.
+pub fn collect_numbers(value: &Value, out: &mut Vec<f64>) {
+    match value {
+        Value::Number(n) => {
+            if let Some(f) = n.as_f64() {
+                if f.is_finite() {
+                    out.push(f);
+                }
+            }
+        }
+        Value::String(s) => {
+            if let Ok(f) = s.parse::<f64>() {
+                if f.is_finite() {
+                    out.push(f);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_numbers(item, out);
+            }
+        }
+        Value::Object(map) => {
+            for item in map.values() {
+                collect_numbers(item, out);
+            }
+        }
+        _ => {}
+    }
+}
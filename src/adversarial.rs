@@ -0,0 +1,302 @@
+//! Adversarial "conformance stress test" generation mode.
+//!
+//! Starting from a real dataset, greedily nudges the value whose leading
+//! digit is most mismatched with Benford's law, picking the smallest
+//! relative rescale that moves chi-square toward (`Conform`) or away from
+//! (`Violate`) a target p-value, until that target is reached or the
+//! perturbation budget runs out. Built for stress-testing fraud detectors
+//! with minimally-altered, close-to-real adversarial samples.
+
+use crate::stats::chi_square_p_value;
+use lawkit_core::{law, LawkitResult};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use serde_json::Value;
+
+/// Which way to push the dataset's Benford conformance.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum StressDirection {
+    /// Nudge values so the chi-square test passes (p-value rises).
+    Conform,
+    /// Nudge values so the chi-square test fails (p-value falls).
+    Violate,
+}
+
+impl StressDirection {
+    pub fn parse(value: &str) -> PyResult<Self> {
+        match value {
+            "conform" => Ok(Self::Conform),
+            "violate" => Ok(Self::Violate),
+            other => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "conformance_direction must be 'conform' or 'violate', got '{other}'"
+            ))),
+        }
+    }
+}
+
+/// Parameters controlling the stress-test perturbation, parsed from the
+/// `law_py` kwargs.
+pub struct StressTestConfig {
+    pub direction: StressDirection,
+    pub target_p_value: f64,
+    pub max_perturbation: f64,
+    pub max_iterations: usize,
+}
+
+/// Outcome of a stress-test run: how many values were touched, the total
+/// relative (L1) cost spent, and the p-value the perturbed data achieves.
+///
+/// `achieved_p_value` is `lawkit_core`'s own Benford p-value for the
+/// perturbed data (the same number a follow-up `law("benf", perturbed)`
+/// call would report), not the Wilson-Hilferty approximation the greedy
+/// search uses internally to decide when to stop -- see `stress_test`.
+pub struct PerturbationSummary {
+    pub values_changed: usize,
+    pub total_l1_cost: f64,
+    pub achieved_p_value: f64,
+}
+
+impl PerturbationSummary {
+    pub fn to_dict(&self, py: Python) -> PyResult<PyObject> {
+        let dict = PyDict::new_bound(py);
+        dict.set_item("values_changed", self.values_changed)?;
+        dict.set_item("total_l1_cost", self.total_l1_cost)?;
+        dict.set_item("achieved_p_value", self.achieved_p_value)?;
+        Ok(dict.to_object(py))
+    }
+}
+
+/// Expected Benford proportion of leading digit `digit` (1-9).
+fn expected_benford(digit: usize) -> f64 {
+    (1.0 + 1.0 / digit as f64).log10()
+}
+
+/// Leading digit (1-9, or 0 if `v` is zero/subnormal) and base-10 order of
+/// magnitude of `v`.
+fn leading_digit_and_order(v: f64) -> (usize, i32) {
+    let magnitude = v.abs();
+    if magnitude < 1e-12 {
+        return (0, 0);
+    }
+    let order = magnitude.log10().floor() as i32;
+    let digit = ((magnitude / 10f64.powi(order)).floor() as usize).clamp(1, 9);
+    (digit, order)
+}
+
+/// The closest value to `v` whose leading digit is `target_digit`, trying
+/// the order of magnitude immediately above and below `order` too -- e.g.
+/// nudging 999 (digit 9) to digit 1 is cheapest by crossing up to 1000, not
+/// by collapsing to 100.
+fn rescale_to_digit(v: f64, target_digit: usize, order: i32) -> f64 {
+    let sign = if v < 0.0 { -1.0 } else { 1.0 };
+    let magnitude = v.abs();
+    let target = target_digit as f64;
+    (order - 1..=order + 1)
+        .map(|o| target * 10f64.powi(o))
+        .min_by(|a, b| {
+            (a - magnitude)
+                .abs()
+                .partial_cmp(&(b - magnitude).abs())
+                .unwrap()
+        })
+        .map(|candidate| sign * candidate)
+        .unwrap_or(v)
+}
+
+/// One real value tracked through the greedy perturbation loop.
+struct Entry {
+    index: usize,
+    value: f64,
+    digit: usize,
+    order: i32,
+}
+
+/// Mutable Benford state: which value sits at which original index, and the
+/// leading-digit histogram they currently produce.
+struct BenfordState {
+    entries: Vec<Entry>,
+    counts: [u64; 9],
+}
+
+impl BenfordState {
+    fn new(values: &[f64]) -> Self {
+        let mut counts = [0u64; 9];
+        let entries = values
+            .iter()
+            .enumerate()
+            .filter_map(|(index, &value)| {
+                let (digit, order) = leading_digit_and_order(value);
+                if digit == 0 {
+                    return None;
+                }
+                counts[digit - 1] += 1;
+                Some(Entry {
+                    index,
+                    value,
+                    digit,
+                    order,
+                })
+            })
+            .collect();
+        Self { entries, counts }
+    }
+
+    fn total(&self) -> f64 {
+        self.entries.len() as f64
+    }
+
+    /// Signed deviation of `digit`'s observed count from its Benford
+    /// expectation; positive means over-represented.
+    fn deviation(&self, digit: usize) -> f64 {
+        self.counts[digit - 1] as f64 - expected_benford(digit) * self.total()
+    }
+
+    fn chi_square(&self) -> f64 {
+        let total = self.total();
+        (1..=9)
+            .map(|digit| {
+                let expected = expected_benford(digit) * total;
+                if expected > 0.0 {
+                    (self.counts[digit - 1] as f64 - expected).powi(2) / expected
+                } else {
+                    0.0
+                }
+            })
+            .sum()
+    }
+
+    /// The cheapest value currently at `source_digit` to move to
+    /// `target_digit`, and its relative (L1) cost.
+    fn cheapest_move(&self, source_digit: usize, target_digit: usize) -> Option<(usize, f64)> {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| entry.digit == source_digit)
+            .map(|(i, entry)| {
+                let rescaled = rescale_to_digit(entry.value, target_digit, entry.order);
+                let cost = (rescaled - entry.value).abs() / entry.value.abs().max(1e-12);
+                (i, cost)
+            })
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+    }
+
+    /// Move `entries[entry_index]` to `target_digit`, returning the
+    /// relative cost spent.
+    fn apply_move(&mut self, entry_index: usize, target_digit: usize) -> f64 {
+        let entry = &mut self.entries[entry_index];
+        let new_value = rescale_to_digit(entry.value, target_digit, entry.order);
+        let cost = (new_value - entry.value).abs() / entry.value.abs().max(1e-12);
+
+        self.counts[entry.digit - 1] -= 1;
+        self.counts[target_digit - 1] += 1;
+        entry.value = new_value;
+        (entry.digit, entry.order) = leading_digit_and_order(new_value);
+
+        cost
+    }
+}
+
+fn most_over_represented_digit(state: &BenfordState) -> usize {
+    (1..=9)
+        .max_by(|&a, &b| state.deviation(a).partial_cmp(&state.deviation(b)).unwrap())
+        .unwrap()
+}
+
+fn most_under_represented_digit(state: &BenfordState, require_values: bool) -> usize {
+    (1..=9)
+        .filter(|&d| !require_values || state.counts[d - 1] > 0)
+        .min_by(|&a, &b| state.deviation(a).partial_cmp(&state.deviation(b)).unwrap())
+        .unwrap_or(1)
+}
+
+/// The actual Benford p-value `lawkit_core` reports for `values`, i.e. what
+/// `law("benf", values)` would compute, for reconciling the greedy search's
+/// own approximation against ground truth. Returns `None` if `law` errors
+/// (e.g. too few values), in which case callers fall back to the
+/// approximation.
+fn lawkit_benford_p_value(values: &[f64]) -> Option<f64> {
+    let data = Value::Array(
+        values
+            .iter()
+            .filter_map(|&v| serde_json::Number::from_f64(v).map(Value::Number))
+            .collect(),
+    );
+    let results = law("benf", &data, None).ok()?;
+    results.into_iter().find_map(|result| match result {
+        LawkitResult::BenfordAnalysis(_, data) => Some(data.p_value),
+        _ => None,
+    })
+}
+
+/// Greedily perturb `values` so Benford's law chi-square moves toward
+/// (`Conform`) or away from (`Violate`) `config.target_p_value`, spending at
+/// most `config.max_perturbation` total relative (L1) change across all
+/// edits.
+///
+/// The search itself is driven by `BenfordState::chi_square` and the
+/// Wilson-Hilferty approximation in `chi_square_p_value` -- cheap to
+/// recompute on every iteration, but not guaranteed to agree exactly with
+/// `lawkit_core`'s own Benford p-value. The final `achieved_p_value`
+/// reported to the caller is instead `lawkit_core`'s real number for the
+/// perturbed data, falling back to the approximation only if that call
+/// fails.
+pub fn stress_test(values: &[f64], config: &StressTestConfig) -> (Vec<f64>, PerturbationSummary) {
+    let mut state = BenfordState::new(values);
+    let mut changed = 0usize;
+    let mut total_cost = 0.0;
+
+    for _ in 0..config.max_iterations {
+        let p_value = chi_square_p_value(state.chi_square(), 8.0);
+        let target_reached = match config.direction {
+            StressDirection::Conform => p_value >= config.target_p_value,
+            StressDirection::Violate => p_value < config.target_p_value,
+        };
+        if target_reached || total_cost >= config.max_perturbation {
+            break;
+        }
+
+        // Conform: drain an over-represented digit into the most
+        // under-represented one. Violate: do the opposite -- deepen an
+        // already under-represented digit's deficit by feeding its values
+        // into the most over-represented digit.
+        let (source_digit, target_digit) = match config.direction {
+            StressDirection::Conform => (
+                most_over_represented_digit(&state),
+                most_under_represented_digit(&state, false),
+            ),
+            StressDirection::Violate => (
+                most_under_represented_digit(&state, true),
+                most_over_represented_digit(&state),
+            ),
+        };
+        if source_digit == target_digit {
+            break;
+        }
+
+        let Some((entry_index, cost)) = state.cheapest_move(source_digit, target_digit) else {
+            break;
+        };
+        if total_cost + cost > config.max_perturbation {
+            break;
+        }
+
+        total_cost += state.apply_move(entry_index, target_digit);
+        changed += 1;
+    }
+
+    let mut perturbed = values.to_vec();
+    for entry in &state.entries {
+        perturbed[entry.index] = entry.value;
+    }
+
+    let achieved_p_value = lawkit_benford_p_value(&perturbed)
+        .unwrap_or_else(|| chi_square_p_value(state.chi_square(), 8.0));
+    (
+        perturbed,
+        PerturbationSummary {
+            values_changed: changed,
+            total_l1_cost: total_cost,
+            achieved_p_value,
+        },
+    )
+}
@@ -0,0 +1,83 @@
+//! Stateful analyzer that separates data ingestion from analysis.
+//!
+//! `law_py` is one-shot: every call re-scans the full dataset for whichever
+//! law is requested. `Analyzer` instead keeps the *sufficient statistics*
+//! for each law (a leading-digit histogram for Benford, a rank-frequency
+//! table for Zipf, running moments for Normal/Poisson) so that `fit`/`update`
+//! fold data in once, and `analyze` can be called for any law, any number of
+//! times, without re-reading prior batches. This is the shape a streaming
+//! monitoring pipeline needs; `law_stream` folds into the same accumulators.
+
+use crate::accumulators::{collect_numbers, DigitHistogram, RankFrequency, WelfordMoments};
+use crate::python_to_value;
+use pyo3::prelude::*;
+use pyo3::types::{PyAny, PyDict, PyList};
+
+/// Accumulates sufficient statistics across `fit`/`update` calls and emits
+/// `law`-shaped result dicts from them via `analyze`, without ever
+/// re-reading the data that produced them.
+#[pyclass]
+#[derive(Default)]
+pub struct Analyzer {
+    benford: DigitHistogram,
+    zipf: RankFrequency,
+    normal: WelfordMoments,
+    poisson: WelfordMoments,
+}
+
+#[pymethods]
+impl Analyzer {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reset accumulated state and ingest `data`. `**kwargs` is accepted for
+    /// parity with `law_py` but currently unused by the accumulators.
+    #[pyo3(signature = (data, **_kwargs))]
+    fn fit(
+        &mut self,
+        py: Python,
+        data: &Bound<'_, PyAny>,
+        _kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<()> {
+        *self = Analyzer::default();
+        self.update(py, data)
+    }
+
+    /// Fold `more_data` into the existing accumulated state, without
+    /// rescanning data already seen by a prior `fit`/`update` call.
+    fn update(&mut self, py: Python, more_data: &Bound<'_, PyAny>) -> PyResult<()> {
+        let value = python_to_value(py, more_data)?;
+        let mut numbers = Vec::new();
+        collect_numbers(&value, &mut numbers);
+
+        for n in numbers {
+            self.benford.observe(n);
+            self.normal.observe(n);
+            self.poisson.observe(n);
+            self.zipf.observe(n);
+        }
+        Ok(())
+    }
+
+    /// Emit the same result-dict shape `law_py` produces for `subcommand`,
+    /// computed from the accumulated sufficient statistics.
+    fn analyze(&self, py: Python, subcommand: &str) -> PyResult<PyObject> {
+        let dict = match subcommand {
+            "benf" => self.benford.analyze(py, "<analyzer>")?,
+            "zipf" => self.zipf.analyze(py, "<analyzer>")?,
+            "normal" => self.normal.analyze_normal(py, "<analyzer>")?,
+            "poisson" => self.poisson.analyze_poisson(py, "<analyzer>")?,
+            other => {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "Analyzer does not support subcommand '{other}'"
+                )))
+            }
+        };
+
+        let py_list = PyList::empty_bound(py);
+        py_list.append(dict)?;
+        Ok(py_list.to_object(py))
+    }
+}
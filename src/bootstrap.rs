@@ -0,0 +1,154 @@
+//! Bootstrap confidence intervals for the numeric statistics `law_py` reports.
+//!
+//! Resamples the input data with replacement, recomputes the requested law's
+//! statistics on each resample, and reports the empirical distribution of
+//! each statistic as a confidence interval rather than a single point
+//! estimate.
+
+use lawkit_core::{law, LawkitOptions, LawkitResult};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Options controlling bootstrap resampling, parsed from the `law_py` kwargs.
+#[derive(Clone, Copy)]
+pub struct BootstrapConfig {
+    pub iterations: usize,
+    pub seed: Option<u64>,
+    pub confidence_level: f64,
+    pub min_sample_size: usize,
+}
+
+/// Map of statistic name -> confidence interval, or `None` when the
+/// resampled data was too small to produce one.
+pub type ConfidenceIntervals = BTreeMap<&'static str, Option<(f64, f64)>>;
+
+/// The numeric statistics eligible for a confidence interval, keyed by the
+/// field name they appear under in `lawkit_result_to_python`.
+fn numeric_fields(result: &LawkitResult) -> Vec<(&'static str, f64)> {
+    match result {
+        LawkitResult::BenfordAnalysis(_, data) => vec![
+            ("chi_square", data.chi_square),
+            ("p_value", data.p_value),
+            ("mad", data.mad),
+        ],
+        LawkitResult::ParetoAnalysis(_, data) => vec![
+            (
+                "top_20_percent_contribution",
+                data.top_20_percent_contribution,
+            ),
+            ("pareto_ratio", data.pareto_ratio),
+            ("concentration_index", data.concentration_index),
+        ],
+        LawkitResult::ZipfAnalysis(_, data) => vec![
+            ("zipf_coefficient", data.zipf_coefficient),
+            ("correlation_coefficient", data.correlation_coefficient),
+            ("deviation_score", data.deviation_score),
+        ],
+        LawkitResult::NormalAnalysis(_, data) => vec![
+            ("mean", data.mean),
+            ("std_dev", data.std_dev),
+            ("skewness", data.skewness),
+            ("kurtosis", data.kurtosis),
+            ("normality_test_p", data.normality_test_p),
+        ],
+        LawkitResult::PoissonAnalysis(_, data) => vec![
+            ("lambda", data.lambda),
+            ("variance_ratio", data.variance_ratio),
+            ("poisson_test_p", data.poisson_test_p),
+        ],
+        _ => Vec::new(),
+    }
+}
+
+/// Resample `data` with replacement `config.iterations` times, recompute
+/// `subcommand`'s statistics on each resample, and return the empirical
+/// confidence interval for every numeric statistic the law exposes.
+///
+/// The field set always matches what `law(subcommand, data, options)`
+/// itself reports (discovered by running it once on the original,
+/// non-resampled data) -- callers get the same keys back whether or not
+/// bootstrapping could actually run, with `None` standing in for a
+/// statistic that couldn't be resampled, rather than that key being
+/// omitted. Also returns every field mapped to `None` when `data` isn't an
+/// array, is empty, or has fewer rows than `config.min_sample_size` -- too
+/// few rows to resample, rather than an error.
+pub fn compute_confidence_intervals(
+    subcommand: &str,
+    data: &Value,
+    options: &LawkitOptions,
+    config: BootstrapConfig,
+) -> ConfidenceIntervals {
+    let field_names: Vec<&'static str> = match law(subcommand, data, Some(options)) {
+        Ok(results) => results
+            .iter()
+            .flat_map(numeric_fields)
+            .map(|(name, _)| name)
+            .collect(),
+        Err(_) => return ConfidenceIntervals::new(),
+    };
+    let none_for_all_fields = || field_names.iter().map(|&name| (name, None)).collect();
+
+    let Some(rows) = data.as_array() else {
+        return none_for_all_fields();
+    };
+    if rows.is_empty() || rows.len() < config.min_sample_size {
+        return none_for_all_fields();
+    }
+
+    let mut rng = match config.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+
+    let mut samples: BTreeMap<&'static str, Vec<f64>> = BTreeMap::new();
+    for _ in 0..config.iterations {
+        let resample: Vec<Value> = (0..rows.len())
+            .map(|_| rows[rng.gen_range(0..rows.len())].clone())
+            .collect();
+
+        let Ok(results) = law(subcommand, &Value::Array(resample), Some(options)) else {
+            continue;
+        };
+        for result in &results {
+            for (name, value) in numeric_fields(result) {
+                samples.entry(name).or_default().push(value);
+            }
+        }
+    }
+
+    let lower_q = (1.0 - config.confidence_level) / 2.0;
+    let upper_q = 1.0 - lower_q;
+
+    field_names
+        .into_iter()
+        .map(|name| {
+            let ci = samples.get(name).and_then(|values| {
+                if values.len() < config.min_sample_size {
+                    return None;
+                }
+                let mut sorted = values.clone();
+                sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                Some((percentile(&sorted, lower_q), percentile(&sorted, upper_q)))
+            });
+            (name, ci)
+        })
+        .collect()
+}
+
+/// Linear-interpolated percentile `q` (in `[0, 1]`) over an already-sorted slice.
+fn percentile(sorted: &[f64], q: f64) -> f64 {
+    if sorted.len() == 1 {
+        return sorted[0];
+    }
+    let pos = q * (sorted.len() - 1) as f64;
+    let lo = pos.floor() as usize;
+    let hi = pos.ceil() as usize;
+    if lo == hi {
+        sorted[lo]
+    } else {
+        let frac = pos - lo as f64;
+        sorted[lo] * (1.0 - frac) + sorted[hi] * frac
+    }
+}
@@ -1,12 +1,25 @@
 #![allow(clippy::useless_conversion)]
 
+mod accumulators;
+mod adversarial;
+mod analyzer;
+mod bootstrap;
+mod pydata;
+mod stats;
+mod streaming;
+
+use adversarial::{StressDirection, StressTestConfig};
+use analyzer::Analyzer;
+use bootstrap::{BootstrapConfig, ConfidenceIntervals};
 use lawkit_core::{law, LawkitOptions, LawkitResult, LawkitSpecificOptions};
+use pydata::PyDataInput;
 use pyo3::prelude::*;
 use pyo3::types::{PyAny, PyDict};
 use serde_json::Value;
+use streaming::law_stream_py;
 
 /// Convert Python object to serde_json::Value
-fn python_to_value(_py: Python, obj: &Bound<'_, PyAny>) -> PyResult<Value> {
+pub(crate) fn python_to_value(_py: Python, obj: &Bound<'_, PyAny>) -> PyResult<Value> {
     if obj.is_none() {
         Ok(Value::Null)
     } else if let Ok(b) = obj.extract::<bool>() {
@@ -75,8 +88,41 @@ fn value_to_python(py: Python, value: &Value) -> PyResult<PyObject> {
     }
 }
 
+/// Set `{field}_ci_low` / `{field}_ci_high` on `dict` from a bootstrap
+/// confidence interval, if one was computed for `field`. A no-op when `ci`
+/// is `None` or doesn't cover `field`.
+fn set_ci_fields(
+    dict: &Bound<'_, PyDict>,
+    py: Python,
+    ci: Option<&ConfidenceIntervals>,
+    field: &str,
+) -> PyResult<()> {
+    let Some(bounds) = ci.and_then(|ci| ci.get(field)) else {
+        return Ok(());
+    };
+    match bounds {
+        Some((low, high)) => {
+            dict.set_item(format!("{field}_ci_low"), low)?;
+            dict.set_item(format!("{field}_ci_high"), high)?;
+        }
+        None => {
+            dict.set_item(format!("{field}_ci_low"), py.None())?;
+            dict.set_item(format!("{field}_ci_high"), py.None())?;
+        }
+    }
+    Ok(())
+}
+
 /// Convert LawkitResult to Python dictionary
-fn lawkit_result_to_python(py: Python, result: &LawkitResult) -> PyResult<PyObject> {
+///
+/// `ci` carries bootstrap confidence intervals (see the `bootstrap` module)
+/// for the numeric statistics of `result`, keyed by field name. Pass `None`
+/// when bootstrapping wasn't requested.
+fn lawkit_result_to_python(
+    py: Python,
+    result: &LawkitResult,
+    ci: Option<&ConfidenceIntervals>,
+) -> PyResult<PyObject> {
     let dict = pyo3::types::PyDict::new_bound(py);
 
     match result {
@@ -97,6 +143,9 @@ fn lawkit_result_to_python(py: Python, result: &LawkitResult) -> PyResult<PyObje
             dict.set_item("risk_level", &data.risk_level)?;
             dict.set_item("total_numbers", data.total_numbers)?;
             dict.set_item("analysis_summary", &data.analysis_summary)?;
+            set_ci_fields(&dict, py, ci, "chi_square")?;
+            set_ci_fields(&dict, py, ci, "p_value")?;
+            set_ci_fields(&dict, py, ci, "mad")?;
         }
         LawkitResult::ParetoAnalysis(path, data) => {
             dict.set_item("type", "ParetoAnalysis")?;
@@ -110,6 +159,9 @@ fn lawkit_result_to_python(py: Python, result: &LawkitResult) -> PyResult<PyObje
             dict.set_item("risk_level", &data.risk_level)?;
             dict.set_item("total_items", data.total_items)?;
             dict.set_item("analysis_summary", &data.analysis_summary)?;
+            set_ci_fields(&dict, py, ci, "top_20_percent_contribution")?;
+            set_ci_fields(&dict, py, ci, "pareto_ratio")?;
+            set_ci_fields(&dict, py, ci, "concentration_index")?;
         }
         LawkitResult::ZipfAnalysis(path, data) => {
             dict.set_item("type", "ZipfAnalysis")?;
@@ -120,6 +172,9 @@ fn lawkit_result_to_python(py: Python, result: &LawkitResult) -> PyResult<PyObje
             dict.set_item("risk_level", &data.risk_level)?;
             dict.set_item("total_items", data.total_items)?;
             dict.set_item("analysis_summary", &data.analysis_summary)?;
+            set_ci_fields(&dict, py, ci, "zipf_coefficient")?;
+            set_ci_fields(&dict, py, ci, "correlation_coefficient")?;
+            set_ci_fields(&dict, py, ci, "deviation_score")?;
         }
         LawkitResult::NormalAnalysis(path, data) => {
             dict.set_item("type", "NormalAnalysis")?;
@@ -132,6 +187,11 @@ fn lawkit_result_to_python(py: Python, result: &LawkitResult) -> PyResult<PyObje
             dict.set_item("risk_level", &data.risk_level)?;
             dict.set_item("total_numbers", data.total_numbers)?;
             dict.set_item("analysis_summary", &data.analysis_summary)?;
+            set_ci_fields(&dict, py, ci, "mean")?;
+            set_ci_fields(&dict, py, ci, "std_dev")?;
+            set_ci_fields(&dict, py, ci, "skewness")?;
+            set_ci_fields(&dict, py, ci, "kurtosis")?;
+            set_ci_fields(&dict, py, ci, "normality_test_p")?;
         }
         LawkitResult::PoissonAnalysis(path, data) => {
             dict.set_item("type", "PoissonAnalysis")?;
@@ -142,6 +202,9 @@ fn lawkit_result_to_python(py: Python, result: &LawkitResult) -> PyResult<PyObje
             dict.set_item("risk_level", &data.risk_level)?;
             dict.set_item("total_events", data.total_events)?;
             dict.set_item("analysis_summary", &data.analysis_summary)?;
+            set_ci_fields(&dict, py, ci, "lambda")?;
+            set_ci_fields(&dict, py, ci, "variance_ratio")?;
+            set_ci_fields(&dict, py, ci, "poisson_test_p")?;
         }
         LawkitResult::IntegrationAnalysis(path, data) => {
             dict.set_item("type", "IntegrationAnalysis")?;
@@ -221,13 +284,18 @@ fn law_py(
     data_or_config: &Bound<'_, PyAny>,
     kwargs: Option<&Bound<'_, PyDict>>,
 ) -> PyResult<PyObject> {
-    // Convert Python objects to serde_json::Value
-    let data_value = python_to_value(py, data_or_config)?;
-
     // Build options from kwargs
     let mut options = LawkitOptions::default();
     let mut lawkit_options = LawkitSpecificOptions::default();
     let mut has_lawkit_options = false;
+    let mut bootstrap_iterations: Option<usize> = None;
+    let mut bootstrap_seed: Option<u64> = None;
+    let mut conformance_target: Option<String> = None;
+    let mut conformance_direction = "conform".to_string();
+    let mut target_p_value: Option<f64> = None;
+    let mut max_perturbation = 1.0;
+    let mut max_iterations: usize = 1000;
+    let mut column: Option<String> = None;
 
     if let Some(kwargs) = kwargs {
         for (key, value) in kwargs.iter() {
@@ -341,6 +409,50 @@ fn law_py(
                         has_lawkit_options = true;
                     }
                 }
+                // Bootstrap confidence intervals, off by default
+                "bootstrap_iterations" => {
+                    if let Ok(iterations) = value.extract::<usize>() {
+                        bootstrap_iterations = Some(iterations);
+                    }
+                }
+                "bootstrap_seed" => {
+                    if let Ok(seed) = value.extract::<u64>() {
+                        bootstrap_seed = Some(seed);
+                    }
+                }
+                // Adversarial conformance stress test, only meaningful with
+                // subcommand == "generate"
+                "conformance_target" => {
+                    if let Ok(target) = value.extract::<String>() {
+                        conformance_target = Some(target);
+                    }
+                }
+                "conformance_direction" => {
+                    if let Ok(direction) = value.extract::<String>() {
+                        conformance_direction = direction;
+                    }
+                }
+                "target_p_value" => {
+                    if let Ok(p) = value.extract::<f64>() {
+                        target_p_value = Some(p);
+                    }
+                }
+                "max_perturbation" => {
+                    if let Ok(budget) = value.extract::<f64>() {
+                        max_perturbation = budget;
+                    }
+                }
+                "max_iterations" => {
+                    if let Ok(iterations) = value.extract::<usize>() {
+                        max_iterations = iterations;
+                    }
+                }
+                // DataFrame column selection for direct pandas ingestion
+                "column" => {
+                    if let Ok(col) = value.extract::<String>() {
+                        column = Some(col);
+                    }
+                }
                 _ => {
                     // Ignore unknown options
                 }
@@ -348,19 +460,156 @@ fn law_py(
         }
     }
 
+    let confidence_level = lawkit_options.confidence_level.unwrap_or(0.95);
+    let min_sample_size = lawkit_options.min_sample_size.unwrap_or(2);
+
     if has_lawkit_options {
         options.lawkit_options = Some(lawkit_options);
     }
 
-    // Perform law analysis
-    let results = law(subcommand, &data_value, Some(&options)).map_err(|e| {
+    // Adversarial conformance stress test: `data_or_config` is a real
+    // dataset to perturb, not a generation config, so this bypasses the
+    // normal `law()` generation path (and the fast-path dispatch below)
+    // entirely. Checked first so NumPy arrays and pandas DataFrame/Series
+    // inputs reach the stress test instead of silently falling through to
+    // a plain `law("generate", ...)` call.
+    if subcommand == "generate" {
+        if let Some(target_law) = &conformance_target {
+            if target_law != "benf" {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "conformance_target '{target_law}' is not supported; only 'benf' is"
+                )));
+            }
+            let data_value = match pydata::convert_fast_path(data_or_config, column.as_deref())? {
+                Some(PyDataInput::Single(value)) => value,
+                Some(PyDataInput::Columns(_)) => {
+                    return Err(pyo3::exceptions::PyValueError::new_err(
+                        "conformance stress test requires a single array; pass column= to select one DataFrame column",
+                    ));
+                }
+                None => python_to_value(py, data_or_config)?,
+            };
+            let mut numbers = Vec::new();
+            accumulators::collect_numbers(&data_value, &mut numbers);
+
+            let config = StressTestConfig {
+                direction: StressDirection::parse(&conformance_direction)?,
+                target_p_value: target_p_value.unwrap_or(0.05),
+                max_perturbation,
+                max_iterations,
+            };
+            let (perturbed, summary) = adversarial::stress_test(&numbers, &config);
+
+            let dict = pyo3::types::PyDict::new_bound(py);
+            dict.set_item("type", "GeneratedData")?;
+            dict.set_item("path", "<generated>")?;
+            dict.set_item("data_type", format!("{target_law}_conformance_stress_test"))?;
+            dict.set_item("count", perturbed.len())?;
+            dict.set_item("parameters", pyo3::types::PyDict::new_bound(py))?;
+            dict.set_item("sample_data", perturbed)?;
+            dict.set_item("perturbation_summary", summary.to_dict(py)?)?;
+
+            let py_list = pyo3::types::PyList::empty_bound(py);
+            py_list.append(dict)?;
+            return Ok(py_list.to_object(py));
+        }
+    }
+
+    // Recognize NumPy arrays and pandas DataFrame/Series directly, pulling
+    // their buffers via the array protocol instead of falling through to
+    // `python_to_value`'s per-element extraction.
+    let columns = match pydata::convert_fast_path(data_or_config, column.as_deref())? {
+        Some(PyDataInput::Columns(columns)) => Some(columns),
+        Some(PyDataInput::Single(value)) => {
+            return finish(
+                py,
+                subcommand,
+                value,
+                &options,
+                bootstrap_iterations,
+                bootstrap_seed,
+                confidence_level,
+                min_sample_size,
+                None,
+            );
+        }
+        None => None,
+    };
+    if let Some(columns) = columns {
+        let py_list = pyo3::types::PyList::empty_bound(py);
+        for (name, value) in &columns {
+            let result = finish(
+                py,
+                subcommand,
+                value.clone(),
+                &options,
+                bootstrap_iterations,
+                bootstrap_seed,
+                confidence_level,
+                min_sample_size,
+                Some(name.as_str()),
+            )?;
+            let result_list = result.downcast_bound::<pyo3::types::PyList>(py)?;
+            for item in result_list.iter() {
+                py_list.append(item)?;
+            }
+        }
+        return Ok(py_list.to_object(py));
+    }
+
+    let data_value = python_to_value(py, data_or_config)?;
+
+    finish(
+        py,
+        subcommand,
+        data_value,
+        &options,
+        bootstrap_iterations,
+        bootstrap_seed,
+        confidence_level,
+        min_sample_size,
+        None,
+    )
+}
+
+/// Run `law()` over `data_value` and convert the results to the Python list
+/// `law_py` returns, optionally overriding each result's `path` (used when
+/// analyzing one column of a DataFrame at a time).
+#[allow(clippy::too_many_arguments)]
+fn finish(
+    py: Python,
+    subcommand: &str,
+    data_value: Value,
+    options: &LawkitOptions,
+    bootstrap_iterations: Option<usize>,
+    bootstrap_seed: Option<u64>,
+    confidence_level: f64,
+    min_sample_size: usize,
+    path_override: Option<&str>,
+) -> PyResult<PyObject> {
+    let results = law(subcommand, &data_value, Some(options)).map_err(|e| {
         pyo3::exceptions::PyRuntimeError::new_err(format!("Law analysis error: {e:?}"))
     })?;
 
-    // Convert results to Python objects
+    // When requested, resample `data_value` to report a confidence interval
+    // alongside every numeric statistic instead of a single point estimate.
+    let ci = bootstrap_iterations.map(|iterations| {
+        let config = BootstrapConfig {
+            iterations,
+            seed: bootstrap_seed,
+            confidence_level,
+            min_sample_size,
+        };
+        bootstrap::compute_confidence_intervals(subcommand, &data_value, options, config)
+    });
+
     let py_list = pyo3::types::PyList::empty_bound(py);
     for result in results {
-        py_list.append(lawkit_result_to_python(py, &result)?)?;
+        let dict = lawkit_result_to_python(py, &result, ci.as_ref())?;
+        if let Some(path) = path_override {
+            dict.downcast_bound::<PyDict>(py)?.set_item("path", path)?;
+        }
+        py_list.append(dict)?;
     }
 
     Ok(py_list.to_object(py))
@@ -370,6 +619,8 @@ fn law_py(
 #[pymodule]
 fn _lawkit(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(law_py, m)?)?;
+    m.add_function(wrap_pyfunction!(law_stream_py, m)?)?;
+    m.add_class::<Analyzer>()?;
     m.add("__version__", "2.6.0")?;
     Ok(())
 }
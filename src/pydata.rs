@@ -0,0 +1,101 @@
+//! Fast ingestion of NumPy arrays and pandas DataFrame/Series objects.
+//!
+//! `python_to_value` converts Python containers to `serde_json::Value` one
+//! element at a time via `extract`, which is the right default for small
+//! nested structures but needlessly slow for a large homogeneous numeric
+//! buffer. This module adds a pre-check that recognizes those buffer-backed
+//! inputs and pulls the data out via the array protocol instead, so
+//! data-science users don't need to call `.tolist()` first.
+
+use numpy::PyReadonlyArrayDyn;
+use pyo3::prelude::*;
+use pyo3::types::PyAny;
+use serde_json::Value;
+
+/// Result of the fast-path conversion: either a single array of values, or
+/// (for a DataFrame with no `column` selected) one array per numeric
+/// column, each tagged with its column name for the result `path`.
+pub enum PyDataInput {
+    Single(Value),
+    Columns(Vec<(String, Value)>),
+}
+
+/// Try to convert `obj` via the array protocol / pandas duck typing. Returns
+/// `Ok(None)` for anything else, so the caller falls back to the regular
+/// per-element `python_to_value` path.
+pub fn convert_fast_path(
+    obj: &Bound<'_, PyAny>,
+    column: Option<&str>,
+) -> PyResult<Option<PyDataInput>> {
+    if is_dataframe(obj)? {
+        return dataframe_to_input(obj, column).map(Some);
+    }
+    if is_series(obj)? {
+        return ndarray_to_value(&obj.call_method0("to_numpy")?)
+            .map(|v| v.map(PyDataInput::Single));
+    }
+    Ok(ndarray_to_value(obj)?.map(PyDataInput::Single))
+}
+
+fn is_dataframe(obj: &Bound<'_, PyAny>) -> PyResult<bool> {
+    Ok(obj.hasattr("columns")? && obj.hasattr("to_numpy")?)
+}
+
+fn is_series(obj: &Bound<'_, PyAny>) -> PyResult<bool> {
+    Ok(!obj.hasattr("columns")? && obj.hasattr("to_numpy")? && obj.hasattr("name")?)
+}
+
+fn dataframe_to_input(obj: &Bound<'_, PyAny>, column: Option<&str>) -> PyResult<PyDataInput> {
+    if let Some(column) = column {
+        let series = obj.get_item(column)?;
+        let array = series.call_method0("to_numpy")?;
+        let value = ndarray_to_value(&array)?.ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err(format!("column '{column}' is not numeric"))
+        })?;
+        return Ok(PyDataInput::Single(value));
+    }
+
+    let column_names: Vec<String> = obj.getattr("columns")?.extract()?;
+    let mut columns = Vec::new();
+    for name in column_names {
+        let series = obj.get_item(&name)?;
+        let array = series.call_method0("to_numpy")?;
+        // Skip non-numeric columns (object/string dtype) rather than
+        // erroring, matching the "analyzed per-column" default behavior.
+        if let Some(value) = ndarray_to_value(&array)? {
+            columns.push((name, value));
+        }
+    }
+    Ok(PyDataInput::Columns(columns))
+}
+
+/// Pull a NumPy array's buffer directly into a `Value::Array` of numbers,
+/// bypassing per-element `extract`. Returns `Ok(None)` if `obj` isn't
+/// convertible to a `f64` array (not a NumPy array, or a genuinely
+/// non-numeric dtype), so the caller can fall back.
+fn ndarray_to_value(obj: &Bound<'_, PyAny>) -> PyResult<Option<Value>> {
+    if let Ok(array) = obj.extract::<PyReadonlyArrayDyn<f64>>() {
+        return Ok(Some(array_to_value(&array)));
+    }
+    // Not already float64 -- `np.array([1, 2, 3])` is int64 by default, and
+    // bool/int32/etc. arrays are common too. Let NumPy cast it; this only
+    // succeeds for genuinely numeric dtypes, so object/string arrays still
+    // fall through to `None`.
+    let Ok(cast) = obj.call_method1("astype", ("float64",)) else {
+        return Ok(None);
+    };
+    let Ok(array) = cast.extract::<PyReadonlyArrayDyn<f64>>() else {
+        return Ok(None);
+    };
+    Ok(Some(array_to_value(&array)))
+}
+
+fn array_to_value(array: &PyReadonlyArrayDyn<f64>) -> Value {
+    Value::Array(
+        array
+            .as_array()
+            .iter()
+            .filter_map(|&f| serde_json::Number::from_f64(f).map(Value::Number))
+            .collect(),
+    )
+}
@@ -0,0 +1,51 @@
+//! Small self-contained statistical helpers shared by law implementations
+//! that need to assess significance without depending on the raw dataset
+//! (e.g. `Analyzer`, which only has accumulated sufficient statistics).
+
+/// Standard normal CDF via the Abramowitz & Stegun erf approximation
+/// (formula 7.1.26, max absolute error 1.5e-7).
+pub fn normal_cdf(z: f64) -> f64 {
+    0.5 * (1.0 + erf(z / std::f64::consts::SQRT_2))
+}
+
+fn erf(x: f64) -> f64 {
+    let sign = if x < 0.0 { -1.0 } else { 1.0 };
+    let x = x.abs();
+
+    let a1 = 0.254829592;
+    let a2 = -0.284496736;
+    let a3 = 1.421413741;
+    let a4 = -1.453152027;
+    let a5 = 1.061405429;
+    let p = 0.3275911;
+
+    let t = 1.0 / (1.0 + p * x);
+    let y = 1.0 - (((((a5 * t + a4) * t) + a3) * t + a2) * t + a1) * t * (-x * x).exp();
+    sign * y
+}
+
+/// Upper-tail p-value for a chi-square statistic, via the Wilson-Hilferty
+/// cube-root normal approximation. Good enough for risk-level bucketing
+/// without pulling in a full statistics crate.
+pub fn chi_square_p_value(chi_square: f64, df: f64) -> f64 {
+    if df <= 0.0 || chi_square <= 0.0 {
+        return 1.0;
+    }
+    let h = 2.0 / (9.0 * df);
+    let z = ((chi_square / df).powf(1.0 / 3.0) - (1.0 - h)) / h.sqrt();
+    (1.0 - normal_cdf(z)).clamp(0.0, 1.0)
+}
+
+/// Bucket a p-value into the same risk-level vocabulary used across the
+/// law result types.
+pub fn risk_level_from_p(p_value: f64) -> &'static str {
+    if p_value < 0.01 {
+        "Critical"
+    } else if p_value < 0.05 {
+        "High"
+    } else if p_value < 0.1 {
+        "Medium"
+    } else {
+        "Low"
+    }
+}
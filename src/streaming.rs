@@ -0,0 +1,143 @@
+//! Out-of-core ingestion of Python iterators/generators.
+//!
+//! `law_py` requires the whole dataset up front: `python_to_value` walks a
+//! fully-materialized Python object. `law_stream` instead pulls a Python
+//! iterable in `batch_size`-sized chunks and folds each chunk into the same
+//! incremental accumulators `Analyzer` uses, so a multi-gigabyte CSV/log
+//! stream or database cursor never has to be collected into one giant
+//! Python list.
+
+use crate::accumulators::{collect_numbers, DigitHistogram, RankFrequency, WelfordMoments};
+use crate::python_to_value;
+use pyo3::prelude::*;
+use pyo3::types::{PyAny, PyDict, PyList};
+
+/// The single accumulator relevant to `subcommand`, so a stream only ever
+/// holds the sufficient statistics for the law actually requested.
+enum StreamAccumulator {
+    Benford(DigitHistogram),
+    Zipf(RankFrequency),
+    Normal(WelfordMoments),
+    Poisson(WelfordMoments),
+}
+
+impl StreamAccumulator {
+    fn for_subcommand(subcommand: &str) -> PyResult<Self> {
+        match subcommand {
+            "benf" => Ok(Self::Benford(DigitHistogram::default())),
+            "zipf" => Ok(Self::Zipf(RankFrequency::default())),
+            "normal" => Ok(Self::Normal(WelfordMoments::default())),
+            "poisson" => Ok(Self::Poisson(WelfordMoments::default())),
+            other => Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "law_stream does not support subcommand '{other}'"
+            ))),
+        }
+    }
+
+    fn ingest_batch(&mut self, numbers: &[f64]) {
+        match self {
+            Self::Benford(hist) => numbers.iter().for_each(|&n| hist.observe(n)),
+            Self::Zipf(freq) => numbers.iter().for_each(|&n| freq.observe(n)),
+            Self::Normal(moments) | Self::Poisson(moments) => {
+                numbers.iter().for_each(|&n| moments.observe(n))
+            }
+        }
+    }
+
+    fn analyze(&self, py: Python) -> PyResult<PyObject> {
+        match self {
+            Self::Benford(hist) => hist.analyze(py, "<stream>"),
+            Self::Zipf(freq) => freq.analyze(py, "<stream>"),
+            Self::Normal(moments) => moments.analyze_normal(py, "<stream>"),
+            Self::Poisson(moments) => moments.analyze_poisson(py, "<stream>"),
+        }
+    }
+}
+
+/// Stream `iterable` through `subcommand`'s analysis in bounded memory.
+///
+/// # Arguments
+///
+/// * `subcommand` - one of "benf", "zipf", "normal", "poisson"
+/// * `iterable` - a Python iterator/generator of numbers or records
+/// * `**kwargs` - `batch_size` (items converted per chunk, default 10000),
+///   `memory_limit_mb` and `use_memory_optimization`, matching `law_py`'s
+///   options of the same name
+///
+/// # Returns
+///
+/// List containing a single analysis result dict, in the same shape `law_py`
+/// returns.
+#[pyfunction(name = "law_stream")]
+#[pyo3(signature = (subcommand, iterable, **kwargs))]
+pub fn law_stream_py(
+    py: Python,
+    subcommand: &str,
+    iterable: &Bound<'_, PyAny>,
+    kwargs: Option<&Bound<'_, PyDict>>,
+) -> PyResult<PyObject> {
+    let mut batch_size: usize = 10_000;
+    let mut memory_limit_mb: Option<usize> = None;
+    let mut use_memory_optimization = true;
+
+    if let Some(kwargs) = kwargs {
+        for (key, value) in kwargs.iter() {
+            match key.extract::<String>()?.as_str() {
+                "batch_size" => {
+                    if let Ok(size) = value.extract::<usize>() {
+                        batch_size = size.max(1);
+                    }
+                }
+                "memory_limit_mb" => {
+                    if let Ok(limit) = value.extract::<usize>() {
+                        memory_limit_mb = Some(limit);
+                    }
+                }
+                "use_memory_optimization" => {
+                    if let Ok(opt) = value.extract::<bool>() {
+                        use_memory_optimization = opt;
+                    }
+                }
+                _ => {
+                    // Ignore unknown options, matching law_py
+                }
+            }
+        }
+    }
+    // Each batch is converted and folded in before the next is pulled, so
+    // at most `batch_size` raw items are ever materialized at once; a
+    // memory cap just shrinks that window further.
+    if !use_memory_optimization {
+        batch_size = batch_size.max(memory_limit_mb.unwrap_or(batch_size));
+    } else if let Some(limit_mb) = memory_limit_mb {
+        batch_size = batch_size.min(limit_mb.saturating_mul(1000).max(1));
+    }
+
+    let mut accumulator = StreamAccumulator::for_subcommand(subcommand)?;
+    let mut batch = Vec::with_capacity(batch_size);
+    let mut numbers = Vec::new();
+
+    for item in iterable.iter()? {
+        let value = python_to_value(py, &item?)?;
+        batch.push(value);
+        if batch.len() >= batch_size {
+            for value in &batch {
+                collect_numbers(value, &mut numbers);
+            }
+            accumulator.ingest_batch(&numbers);
+            batch.clear();
+            numbers.clear();
+        }
+    }
+    if !batch.is_empty() {
+        for value in &batch {
+            collect_numbers(value, &mut numbers);
+        }
+        accumulator.ingest_batch(&numbers);
+    }
+
+    let dict = accumulator.analyze(py)?;
+    let py_list = PyList::empty_bound(py);
+    py_list.append(dict)?;
+    Ok(py_list.to_object(py))
+}